@@ -9,22 +9,309 @@
 //! - Batch accumulation for efficient Lance dataset writing
 //! - Tokio async runtime for Lance write operations
 //! - Comprehensive error handling and logging
-
+//!
+//! ## The `c-unwind` feature
+//!
+//! By default every exported function is `extern "C"`: a Rust panic that
+//! escapes it is caught by [`ffi_guard!`] (or, if linked `panic=abort`,
+//! aborts the process outright) -- it never unwinds into the C++ caller.
+//! Enabling the `c-unwind` Cargo feature adds an `_unwind`-suffixed
+//! `extern "C-unwind"` sibling for each entry point that lets a panic
+//! legitimately propagate as a foreign exception, for embedders whose whole
+//! binary is linked `panic=unwind`. Mixing a `C-unwind` call into a
+//! `panic=abort` binary is undefined behavior, so enabling this feature
+//! requires a uniform `panic=unwind` link; see the `compile_error!` guard
+//! below.
+//!
+//! NOTE: this source tree currently has no `Cargo.toml` manifest to declare
+//! `c-unwind = []` in a `[features]` table. The feature-gated code below is
+//! written as it would be used once a manifest exists.
+
+#[cfg(all(feature = "c-unwind", panic = "abort"))]
+compile_error!(
+    "the `c-unwind` feature requires this crate (and everything it's linked into) to build with \
+     panic=unwind -- extern \"C-unwind\" functions are undefined behavior if a panic can abort \
+     while unwinding through them"
+);
+
+use std::any::Any;
 use std::ffi::CStr;
 use std::os::raw::{c_char, c_int, c_void};
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr::NonNull;
 use std::sync::Arc;
 use std::slice;
 use std::collections::HashMap;
 
 use arrow::ffi::{FFI_ArrowSchema, FFI_ArrowArray};
 use arrow::record_batch::RecordBatch;
-use arrow::array::{RecordBatchIterator, Array, Int64Array, Float64Array, Int32Array, StringArray};
+use arrow::array::{
+    RecordBatchIterator, Array, Int64Array, Float64Array, Float32Array, Int32Array, StringArray,
+    FixedSizeListArray, BooleanArray, Date32Array, Date64Array, Decimal128Array, LargeStringArray,
+    StringViewArray, BinaryViewArray,
+};
 use arrow::datatypes::{Schema, DataType, Field};
 use arrow::buffer::Buffer;
 use arrow::array::ArrayData;
 use tokio::runtime::Runtime;
-use lance::dataset::WriteParams;
+use lance::dataset::{WriteParams, WriteMode};
+use lance_file::version::LanceFileVersion;
+use lance::index::DatasetIndexExt;
+use lance::index::vector::VectorIndexParams;
+use lance_index::IndexType;
+use lance_index::vector::hnsw::builder::HnswBuildParams;
+use lance_index::vector::ivf::IvfBuildParams;
+use lance_index::vector::pq::PQBuildParams;
+use lance_linalg::distance::DistanceType;
+
+/// Status code returned by the entry points that have no domain-specific
+/// return value (`lance_set_log_callback`, `lance_clear_log_callback`,
+/// `lance_clear_last_error`, `lance_writer_destroy`). Entry points that
+/// return a raw pointer or a `c_int` error code do not report a
+/// `LanceStatus`; a panic in any guarded entry point is still recorded for
+/// `lance_last_error_message`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanceStatus {
+    Ok = 0,
+    Error = 1,
+}
+
+/// The last error recorded on this thread: a message (usually a panic
+/// payload downcast to `&str`/`String`) plus an optional backtrace, captured
+/// only when `RUST_BACKTRACE` is set so the common case stays cheap.
+struct LastError {
+    message: String,
+    backtrace: Option<String>,
+}
+
+thread_local! {
+    /// Per-thread last-error slot. Thread-local (not per-writer) because a
+    /// panic can occur before a `LanceWriterHandle` even exists (e.g. inside
+    /// `lance_writer_create`), so there's no handle to attach it to.
+    static LAST_ERROR: std::cell::RefCell<Option<LastError>> = std::cell::RefCell::new(None);
+    /// Backing storage for the `CString` handed out by
+    /// `lance_last_error_message`, kept alive until the next call on this
+    /// thread (or `lance_clear_last_error`) so the returned pointer stays valid.
+    static LAST_ERROR_CSTRING: std::cell::RefCell<Option<std::ffi::CString>> = std::cell::RefCell::new(None);
+}
+
+/// Record `message` as this thread's last error, capturing a backtrace when
+/// `RUST_BACKTRACE` is set in the environment.
+fn set_last_error(message: String) {
+    let backtrace = if std::env::var_os("RUST_BACKTRACE").is_some() {
+        Some(std::backtrace::Backtrace::force_capture().to_string())
+    } else {
+        None
+    };
+    LAST_ERROR.with(|slot| {
+        *slot.borrow_mut() = Some(LastError { message, backtrace });
+    });
+}
+
+/// Downcast a caught panic payload into a human-readable message.
+fn panic_payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Record a caught panic as this thread's last error and log it.
+fn record_panic(function_name: &str, payload: Box<dyn Any + Send>) {
+    let message = panic_payload_message(&*payload);
+    emit_log(LANCE_LOG_LEVEL_ERROR, &format!("Panic in {}: {}", function_name, message));
+    set_last_error(format!("panic in {}: {}", function_name, message));
+}
+
+/// Log severity levels passed to a registered `lance_set_log_callback`
+/// callback, mirroring common C logging conventions.
+pub const LANCE_LOG_LEVEL_ERROR: c_int = 2;
+
+/// A host-supplied diagnostic callback, receiving a severity level and a
+/// null-terminated UTF-8 message.
+type LogCallback = extern "C" fn(level: c_int, msg: *const c_char);
+
+static LOG_CALLBACK: std::sync::Mutex<Option<LogCallback>> = std::sync::Mutex::new(None);
+
+/// Register a callback to receive the message of each panic caught at the
+/// FFI boundary instead of it going to `stderr`. Other diagnostics from this
+/// library are still written to `stderr`. Pass `None`-equivalent by calling
+/// `lance_clear_log_callback` to go back to the default `eprintln!` behavior.
+///
+/// # Returns
+/// `LanceStatus::Ok`, or `LanceStatus::Error` if the callback slot's lock is
+/// poisoned (a prior holder panicked while holding it).
+///
+/// # Safety
+/// `cb` must be safe to call with an `i32` level and a temporary,
+/// null-terminated UTF-8 C string valid only for the duration of the call.
+#[no_mangle]
+pub extern "C" fn lance_set_log_callback(cb: LogCallback) -> LanceStatus {
+    match LOG_CALLBACK.lock() {
+        Ok(mut slot) => {
+            *slot = Some(cb);
+            LanceStatus::Ok
+        }
+        Err(_) => LanceStatus::Error,
+    }
+}
+
+/// Clear a previously registered log callback, reverting to `eprintln!`.
+///
+/// # Returns
+/// `LanceStatus::Ok`, or `LanceStatus::Error` if the callback slot's lock is
+/// poisoned (a prior holder panicked while holding it).
+#[no_mangle]
+pub extern "C" fn lance_clear_log_callback() -> LanceStatus {
+    match LOG_CALLBACK.lock() {
+        Ok(mut slot) => {
+            *slot = None;
+            LanceStatus::Ok
+        }
+        Err(_) => LanceStatus::Error,
+    }
+}
+
+/// Emit a diagnostic message: to the registered callback if one is
+/// installed, else to `stderr` (the original behavior).
+///
+/// The callback is invoked inside its own `catch_unwind` so a misbehaving
+/// host callback that panics can't in turn unwind across this boundary --
+/// it's swallowed and reported to `stderr` instead.
+fn emit_log(level: c_int, message: &str) {
+    let cb = LOG_CALLBACK.lock().ok().and_then(|slot| *slot);
+    match cb {
+        Some(cb) => {
+            let c_message = std::ffi::CString::new(message).unwrap_or_else(|_| {
+                std::ffi::CString::new("<log message contained NUL>").unwrap()
+            });
+            let result = catch_unwind(AssertUnwindSafe(|| cb(level, c_message.as_ptr())));
+            if result.is_err() {
+                eprintln!(
+                    "Lance FFI Error: registered log callback panicked while logging: {}",
+                    message
+                );
+            }
+        }
+        None => eprintln!("Lance FFI Error: {}", message),
+    }
+}
+
+thread_local! {
+    /// Name of the `extern "C"` entry point currently executing on this
+    /// thread, read by the global panic hook below so a panic that aborts
+    /// the process (because it's linked `panic=abort`) still gets a
+    /// function-qualified diagnostic ahead of the default Rust panic message.
+    static CURRENT_FFI_FN: std::cell::Cell<&'static str> = std::cell::Cell::new("");
+}
+
+/// Install a panic hook (once, process-wide) that prefixes the default panic
+/// output with the currently-executing FFI function name, if any is set in
+/// `CURRENT_FFI_FN`.
+///
+/// Only meaningful under `panic=abort`: that's the only linking where
+/// `ffi_guard!` can't `catch_unwind` and record the panic itself, so this is
+/// the sole diagnostic the host ever sees. Under `panic=unwind`, `ffi_guard!`
+/// already reports the panic via `record_panic`; installing this hook there
+/// too would print a second, misleading "cannot unwind" line for a panic that
+/// in fact unwound and was caught, so it's a no-op on that cfg.
+#[cfg(panic = "abort")]
+fn install_panic_hook() {
+    use std::sync::Once;
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let fn_name = CURRENT_FFI_FN.with(|c| c.get());
+            if !fn_name.is_empty() {
+                eprintln!(
+                    "Lance FFI Error: panic in a function that cannot unwind ({}): {}",
+                    fn_name, info
+                );
+            }
+            default_hook(info);
+        }));
+    });
+}
+
+#[cfg(panic = "unwind")]
+fn install_panic_hook() {}
+
+/// Wrap the body of an `extern "C"` entry point so panic-boundary behavior
+/// is defined in exactly one place instead of copy-pasted per function.
+///
+/// Under `panic=unwind`, catches the panic via `catch_unwind` and translates
+/// it into the thread-local last-error channel, returning `$default`.
+/// Under `panic=abort`, `catch_unwind` can never actually catch anything --
+/// the process aborts at the panic site -- so this skips the pointless call
+/// and instead relies on the panic hook (installed just below) to print a
+/// function-name-qualified diagnostic before the unavoidable abort.
+macro_rules! ffi_guard {
+    ($name:expr, $default:expr, $body:expr) => {{
+        install_panic_hook();
+        CURRENT_FFI_FN.with(|c| c.set($name));
+
+        #[cfg(panic = "unwind")]
+        let result = catch_unwind(AssertUnwindSafe($body)).unwrap_or_else(|payload| {
+            record_panic($name, payload);
+            $default
+        });
+        #[cfg(panic = "abort")]
+        let result = ($body)();
+
+        CURRENT_FFI_FN.with(|c| c.set(""));
+        result
+    }};
+}
+
+/// Return the last error message recorded on this (calling) thread, or null
+/// if none is set. The returned pointer is owned by thread-local storage and
+/// stays valid until the next call to this function or to
+/// `lance_clear_last_error` on the same thread -- callers needing it longer
+/// should copy it immediately.
+///
+/// `writer_ptr` is accepted for forward compatibility with a future
+/// per-writer error channel but is currently unused: errors are tracked
+/// per-thread, since a panic can happen before any writer exists.
+///
+/// # Safety
+/// `writer_ptr`, if non-null, must be a valid `LanceWriterHandle` pointer.
+#[no_mangle]
+pub extern "C" fn lance_last_error_message(
+    _writer_ptr: *const LanceWriterHandle,
+) -> *const c_char {
+    LAST_ERROR.with(|slot| match slot.borrow().as_ref() {
+        Some(err) => {
+            let full = match &err.backtrace {
+                Some(bt) => format!("{}\nbacktrace:\n{}", err.message, bt),
+                None => err.message.clone(),
+            };
+            let cstring = std::ffi::CString::new(full)
+                .unwrap_or_else(|_| std::ffi::CString::new("<error message contained NUL>").unwrap());
+            let ptr = cstring.as_ptr();
+            LAST_ERROR_CSTRING.with(|storage| {
+                *storage.borrow_mut() = Some(cstring);
+            });
+            ptr
+        }
+        None => std::ptr::null(),
+    })
+}
+
+/// Clear the last error recorded on this (calling) thread.
+///
+/// # Returns
+/// Always `LanceStatus::Ok`; clearing a thread-local slot cannot fail.
+#[no_mangle]
+pub extern "C" fn lance_clear_last_error() -> LanceStatus {
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
+    LAST_ERROR_CSTRING.with(|storage| *storage.borrow_mut() = None);
+    LanceStatus::Ok
+}
 
 /// C Data Interface ArrowArray structure - matches the C specification
 /// This allows us to access the FFI_ArrowArray fields directly
@@ -42,6 +329,33 @@ struct CDataArrowArray {
     private_data: *mut c_void,
 }
 
+/// Owns a root array's release callback so borrowed buffers can be kept
+/// alive zero-copy: every `Buffer` imported from that root clones this guard
+/// in as its allocation owner, and the callback fires exactly once, when the
+/// last such `Buffer` (across all child arrays) drops.
+///
+/// Mirrors Lance's `LanceBuffer` copy-on-write story, but for data we never
+/// owned in the first place -- it's still the C++ side's memory until the
+/// callback runs.
+struct ReleaseGuard {
+    release: Option<extern "C" fn(*mut c_void)>,
+    private_data: *mut c_void,
+}
+
+// SAFETY: `private_data` is an opaque handle the C Data Interface requires
+// callers to treat as inert outside of invoking `release` on it, which is
+// exactly what `Drop` below does; no other code dereferences it.
+unsafe impl Send for ReleaseGuard {}
+unsafe impl Sync for ReleaseGuard {}
+
+impl Drop for ReleaseGuard {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release(self.private_data);
+        }
+    }
+}
+
 /// Safe wrapper around FFI_ArrowArray C structure
 /// Provides methods to safely read buffer pointers and child arrays
 struct SafeArrowArray {
@@ -108,12 +422,73 @@ impl SafeArrowArray {
             (*self.ffi).null_count
         }
     }
+
+    /// Get the number of buffer pointers (`n_buffers`), e.g. to discover the
+    /// variadic data buffer count on a view type.
+    unsafe fn n_buffers(&self) -> i64 {
+        if self.ffi.is_null() {
+            0
+        } else {
+            (*self.ffi).n_buffers
+        }
+    }
+}
+
+/// Count the zero (null) bits among the first `length` bits of a validity bitmap.
+///
+/// Bit `i` set means valid (non-null), per the Arrow C Data Interface, so the
+/// null count is the number of unset bits -- we trust this over whatever the
+/// FFI side reports in `null_count`, since that field is caller-supplied and
+/// can drift from the bitmap it's paired with.
+fn count_null_bits(bitmap: &[u8], length: usize) -> usize {
+    let mut null_count = 0;
+    for i in 0..length {
+        let byte = bitmap[i / 8];
+        if (byte >> (i % 8)) & 1 == 0 {
+            null_count += 1;
+        }
+    }
+    null_count
+}
+
+/// Wrap a borrowed FFI buffer as a zero-copy `Buffer` that keeps `guard`
+/// alive for as long as the buffer (and any clones of it) exist.
+///
+/// # Safety
+/// `ptr` must be valid for reads of `len` bytes for as long as `guard`'s
+/// release callback has not yet run.
+unsafe fn borrowed_buffer(ptr: *const u8, len: usize, guard: &Arc<ReleaseGuard>) -> Buffer {
+    let non_null = NonNull::new(ptr as *mut u8).expect("buffer_ptr already checked non-null");
+    Buffer::from_custom_allocation(non_null, len, Arc::clone(guard))
+}
+
+/// Read buffer 0 (the validity bitmap) and return it along with a validated
+/// null count, or `(None, 0)` if the array has no validity buffer (a null
+/// buffer 0 pointer).
+///
+/// The bitmap is `ceil(length/8)` bytes; we recompute `null_count` from the
+/// bits themselves rather than trusting the FFI-reported value. The returned
+/// buffer borrows the FFI-owned bytes directly via `guard` instead of copying.
+unsafe fn import_validity(
+    safe_array: &SafeArrowArray,
+    length: usize,
+    guard: &Arc<ReleaseGuard>,
+) -> (Option<Buffer>, usize) {
+    match safe_array.buffer_ptr(0) {
+        Some(ptr) => {
+            let byte_count = (length + 7) / 8; // Ceiling division
+            let null_count = count_null_bits(slice::from_raw_parts(ptr, byte_count), length);
+            (Some(borrowed_buffer(ptr, byte_count, guard)), null_count)
+        }
+        None => (None, 0),
+    }
 }
 
 /// Import a primitive type array (Int64, Float64, Int32)
 fn import_primitive_array(
     safe_array: &SafeArrowArray,
     field: &Field,
+    guard: &Arc<ReleaseGuard>,
 ) -> Result<Arc<dyn Array>, String> {
     unsafe {
         let length = safe_array.length() as usize;
@@ -122,62 +497,88 @@ fn import_primitive_array(
         }
 
         // Buffer 0: Null bitmap (one bit per element)
-        // Read null bitmap if present
-        let _null_bitmap = if let Some(ptr) = safe_array.buffer_ptr(0) {
-            let byte_count = (length + 7) / 8; // Ceiling division
-            let slice = slice::from_raw_parts(ptr, byte_count);
-            Some(Buffer::from_slice_ref(slice))
-        } else {
-            None
-        };
-
-        // Buffer 1: Data values
-        let data_ptr = safe_array
-            .buffer_ptr(1)
-            .ok_or("Missing data buffer for primitive array")?;
-
-        match field.data_type() {
-            DataType::Int64 => {
-                let value_count = length;
-                let byte_count = value_count * std::mem::size_of::<i64>();
-                let slice = slice::from_raw_parts(data_ptr, byte_count);
-                let data_buffer = Buffer::from_slice_ref(slice);
+        let (null_bitmap, null_count) = import_validity(safe_array, length, guard);
+        let all_null = null_count == length;
+
+        macro_rules! import_fixed_width {
+            ($data_type:expr, $elem_size:expr, $array_ty:ty) => {{
+                let byte_count = length * $elem_size;
+
+                let data_buffer = if all_null {
+                    // All-null fast path: values are never observed, so skip
+                    // reading the data buffer entirely and hand Arrow a
+                    // zeroed one (mirrors Lance's AllNullLayout encoding).
+                    Buffer::from(vec![0u8; byte_count])
+                } else {
+                    let data_ptr = safe_array
+                        .buffer_ptr(1)
+                        .ok_or("Missing data buffer for primitive array")?;
+                    borrowed_buffer(data_ptr, byte_count, guard)
+                };
 
-                let array_data = ArrayData::builder(DataType::Int64)
+                let array_data = ArrayData::builder($data_type)
                     .len(length)
-                    .buffers(vec![data_buffer])
-                    .null_count(safe_array.null_count() as usize)
+                    .add_buffer(data_buffer)
+                    .null_bit_buffer(null_bitmap.clone())
+                    .null_count(null_count)
                     .build_unchecked();
 
-                Ok(Arc::new(Int64Array::from(array_data)))
+                Ok(Arc::new(<$array_ty>::from(array_data)) as Arc<dyn Array>)
+            }};
+        }
+
+        match field.data_type() {
+            DataType::Int64 => {
+                import_fixed_width!(DataType::Int64, std::mem::size_of::<i64>(), Int64Array)
             }
             DataType::Float64 => {
-                let value_count = length;
-                let byte_count = value_count * std::mem::size_of::<f64>();
-                let slice = slice::from_raw_parts(data_ptr, byte_count);
-                let data_buffer = Buffer::from_slice_ref(slice);
-
-                let array_data = ArrayData::builder(DataType::Float64)
-                    .len(length)
-                    .buffers(vec![data_buffer])
-                    .null_count(safe_array.null_count() as usize)
-                    .build_unchecked();
-
-                Ok(Arc::new(Float64Array::from(array_data)))
+                import_fixed_width!(DataType::Float64, std::mem::size_of::<f64>(), Float64Array)
             }
             DataType::Int32 => {
-                let value_count = length;
-                let byte_count = value_count * std::mem::size_of::<i32>();
-                let slice = slice::from_raw_parts(data_ptr, byte_count);
-                let data_buffer = Buffer::from_slice_ref(slice);
+                import_fixed_width!(DataType::Int32, std::mem::size_of::<i32>(), Int32Array)
+            }
+            DataType::Float32 => {
+                import_fixed_width!(DataType::Float32, std::mem::size_of::<f32>(), Float32Array)
+            }
+            // Date32/Date64 share Int32/Int64's fixed-width layout (days /
+            // milliseconds since the epoch, respectively) -- only the
+            // logical data type differs.
+            DataType::Date32 => {
+                import_fixed_width!(DataType::Date32, std::mem::size_of::<i32>(), Date32Array)
+            }
+            DataType::Date64 => {
+                import_fixed_width!(DataType::Date64, std::mem::size_of::<i64>(), Date64Array)
+            }
+            // Decimal128: a 16-byte fixed-width values buffer; precision and
+            // scale live in the data type itself, carried over from `field`.
+            DataType::Decimal128(precision, scale) => import_fixed_width!(
+                DataType::Decimal128(*precision, *scale),
+                16,
+                Decimal128Array
+            ),
+            // Boolean is fixed-width at the bit level, not the byte level
+            // (one bit per element, LSB-first), so it can't go through the
+            // `import_fixed_width!` macro above.
+            DataType::Boolean => {
+                let byte_count = (length + 7) / 8;
+
+                let data_buffer = if all_null {
+                    Buffer::from(vec![0u8; byte_count])
+                } else {
+                    let data_ptr = safe_array
+                        .buffer_ptr(1)
+                        .ok_or("Missing data buffer for boolean array")?;
+                    borrowed_buffer(data_ptr, byte_count, guard)
+                };
 
-                let array_data = ArrayData::builder(DataType::Int32)
+                let array_data = ArrayData::builder(DataType::Boolean)
                     .len(length)
-                    .buffers(vec![data_buffer])
-                    .null_count(safe_array.null_count() as usize)
+                    .add_buffer(data_buffer)
+                    .null_bit_buffer(null_bitmap)
+                    .null_count(null_count)
                     .build_unchecked();
 
-                Ok(Arc::new(Int32Array::from(array_data)))
+                Ok(Arc::new(BooleanArray::from(array_data)) as Arc<dyn Array>)
             }
             other => Err(format!(
                 "Unsupported primitive type in FFI import: {}",
@@ -187,10 +588,64 @@ fn import_primitive_array(
     }
 }
 
-/// Import a string/binary type array
+/// Import a `FixedSizeList<Float32>` / `FixedSizeList<Float64>` vector column.
+///
+/// Per the C Data Interface, a `FixedSizeList` array carries only a validity
+/// buffer (buffer 0) and exactly one child array of `length * list_size`
+/// values -- there's no offsets buffer since every element is the same width.
+fn import_fixed_size_list_array(
+    safe_array: &SafeArrowArray,
+    field: &Field,
+    guard: &Arc<ReleaseGuard>,
+) -> Result<Arc<dyn Array>, String> {
+    unsafe {
+        let length = safe_array.length() as usize;
+        if length == 0 {
+            return Err("Cannot import array with 0 length".to_string());
+        }
+
+        let (element_field, list_size) = match field.data_type() {
+            DataType::FixedSizeList(element_field, list_size) => {
+                (element_field.clone(), *list_size)
+            }
+            other => return Err(format!("Expected FixedSizeList type, got {}", other)),
+        };
+
+        match element_field.data_type() {
+            DataType::Float32 | DataType::Float64 => {}
+            other => {
+                return Err(format!(
+                    "Unsupported FixedSizeList element type for vector column: {}",
+                    other
+                ))
+            }
+        }
+
+        let (null_bitmap, null_count) = import_validity(safe_array, length, guard);
+
+        let child_ptr = safe_array
+            .child(0)
+            .ok_or("Missing values child array for FixedSizeList")?;
+        let child_safe = SafeArrowArray { ffi: child_ptr };
+        let values = import_primitive_array(&child_safe, &element_field, guard)?;
+
+        let array_data = ArrayData::builder(DataType::FixedSizeList(element_field, list_size))
+            .len(length)
+            .add_child_data(values.to_data())
+            .null_bit_buffer(null_bitmap)
+            .null_count(null_count)
+            .build_unchecked();
+
+        Ok(Arc::new(FixedSizeListArray::from(array_data)))
+    }
+}
+
+/// Import a string/binary type array (`Utf8` with 32-bit offsets, or
+/// `LargeUtf8` with 64-bit offsets).
 fn import_string_array(
     safe_array: &SafeArrowArray,
-    _field: &Field,
+    field: &Field,
+    guard: &Arc<ReleaseGuard>,
 ) -> Result<Arc<dyn Array>, String> {
     unsafe {
         let length = safe_array.length() as usize;
@@ -198,22 +653,46 @@ fn import_string_array(
             return Err("Cannot import array with 0 length".to_string());
         }
 
-        // Buffer 0: Null bitmap (not included in ArrayData buffers)
-        let _null_bitmap = if let Some(ptr) = safe_array.buffer_ptr(0) {
-            let byte_count = (length + 7) / 8;
-            let slice = slice::from_raw_parts(ptr, byte_count);
-            Some(Buffer::from_slice_ref(slice))
+        let is_large = matches!(field.data_type(), DataType::LargeUtf8);
+        let offset_elem_size = if is_large {
+            std::mem::size_of::<i64>()
         } else {
-            None
+            std::mem::size_of::<i32>()
         };
+        let data_type = if is_large { DataType::LargeUtf8 } else { DataType::Utf8 };
+
+        // Buffer 0: Null bitmap
+        let (null_bitmap, null_count) = import_validity(safe_array, length, guard);
+        let all_null = null_count == length;
+
+        // All-null fast path: an empty string repeated `length` times still
+        // needs `length + 1` zero offsets, but the data buffer is empty --
+        // skip reading buffer 2 from the FFI side entirely.
+        if all_null {
+            let offset_buffer = Buffer::from(vec![0u8; (length + 1) * offset_elem_size]);
+            let data_buffer = Buffer::from(Vec::<u8>::new());
+
+            let array_data = ArrayData::builder(data_type)
+                .len(length)
+                .add_buffer(offset_buffer)
+                .add_buffer(data_buffer)
+                .null_bit_buffer(null_bitmap)
+                .null_count(null_count)
+                .build_unchecked();
+
+            return Ok(if is_large {
+                Arc::new(LargeStringArray::from(array_data))
+            } else {
+                Arc::new(StringArray::from(array_data))
+            });
+        }
 
-        // Buffer 1: Offsets (int32 per element + 1)
+        // Buffer 1: Offsets (int32 per element + 1, or int64 for LargeUtf8)
         let offset_ptr = safe_array
             .buffer_ptr(1)
             .ok_or("Missing offset buffer for string array")?;
-        let offset_byte_count = (length + 1) * std::mem::size_of::<i32>();
-        let offset_slice = slice::from_raw_parts(offset_ptr, offset_byte_count);
-        let offset_buffer = Buffer::from_slice_ref(offset_slice);
+        let offset_byte_count = (length + 1) * offset_elem_size;
+        let offset_buffer = borrowed_buffer(offset_ptr, offset_byte_count, guard);
 
         // Buffer 2: Data bytes
         let data_ptr = safe_array
@@ -221,27 +700,138 @@ fn import_string_array(
             .ok_or("Missing data buffer for string array")?;
 
         // Get total byte length from last offset
-        let offset_i32_slice = slice::from_raw_parts(offset_ptr as *const i32, length + 1);
-        let data_byte_count = if !offset_i32_slice.is_empty() {
-            offset_i32_slice[length] as usize
+        let data_byte_count = if is_large {
+            let offsets = slice::from_raw_parts(offset_ptr as *const i64, length + 1);
+            offsets.last().copied().unwrap_or(0) as usize
         } else {
-            0
+            let offsets = slice::from_raw_parts(offset_ptr as *const i32, length + 1);
+            offsets.last().copied().unwrap_or(0) as usize
         };
 
-        let data_slice = slice::from_raw_parts(data_ptr, data_byte_count);
-        let data_buffer = Buffer::from_slice_ref(data_slice);
+        let data_buffer = borrowed_buffer(data_ptr, data_byte_count, guard);
 
         // String arrays only need offsets and data buffers in ArrayData
-        let array_data = ArrayData::builder(DataType::Utf8)
+        let array_data = ArrayData::builder(data_type)
             .len(length)
             .buffers(vec![
                 offset_buffer,
                 data_buffer,
             ])
-            .null_count(safe_array.null_count() as usize)
+            .null_bit_buffer(null_bitmap)
+            .null_count(null_count)
+            .build_unchecked();
+
+        Ok(if is_large {
+            Arc::new(LargeStringArray::from(array_data))
+        } else {
+            Arc::new(StringArray::from(array_data))
+        })
+    }
+}
+
+/// Import a `Utf8View`/`BinaryView` array.
+///
+/// Per the C Data Interface, a view type carries buffer 0 (validity), buffer
+/// 1 (one 16-byte view struct per element -- inline bytes when the string is
+/// <= 12 bytes, otherwise a `(length, prefix, buffer_index, offset)` pointer
+/// into a variadic data buffer), then N variadic data buffers, then a final
+/// buffer of `N` `i64` byte-lengths for those data buffers (since the C Data
+/// Interface has no other way to convey their sizes). So
+/// `n_buffers == 3 + N`.
+fn import_view_array(
+    safe_array: &SafeArrowArray,
+    is_string: bool,
+    guard: &Arc<ReleaseGuard>,
+) -> Result<Arc<dyn Array>, String> {
+    unsafe {
+        let length = safe_array.length() as usize;
+        if length == 0 {
+            return Err("Cannot import array with 0 length".to_string());
+        }
+
+        let (null_bitmap, null_count) = import_validity(safe_array, length, guard);
+
+        let views_ptr = safe_array
+            .buffer_ptr(1)
+            .ok_or("Missing views buffer for view array")?;
+        let views_byte_count = length * 16;
+        let views_buffer = borrowed_buffer(views_ptr, views_byte_count, guard);
+
+        let n_buffers = safe_array.n_buffers();
+        let num_variadic = if n_buffers >= 3 { (n_buffers - 3) as usize } else { 0 };
+
+        let mut buffers = Vec::with_capacity(1 + num_variadic);
+        buffers.push(views_buffer);
+
+        if num_variadic > 0 {
+            let sizes_ptr = safe_array
+                .buffer_ptr(2 + num_variadic)
+                .ok_or("Missing variadic buffer sizes for view array")?;
+            let sizes = slice::from_raw_parts(sizes_ptr as *const i64, num_variadic);
+
+            for (i, &size) in sizes.iter().enumerate() {
+                let data_ptr = safe_array
+                    .buffer_ptr(2 + i)
+                    .ok_or("Missing variadic data buffer for view array")?;
+                buffers.push(borrowed_buffer(data_ptr, size as usize, guard));
+            }
+        }
+
+        let data_type = if is_string { DataType::Utf8View } else { DataType::BinaryView };
+        let array_data = ArrayData::builder(data_type)
+            .len(length)
+            .buffers(buffers)
+            .null_bit_buffer(null_bitmap)
+            .null_count(null_count)
             .build_unchecked();
 
-        Ok(Arc::new(StringArray::from(array_data)))
+        Ok(if is_string {
+            Arc::new(StringViewArray::from(array_data))
+        } else {
+            Arc::new(BinaryViewArray::from(array_data))
+        })
+    }
+}
+
+/// Write mode for a Lance dataset, mirroring `lance::dataset::WriteMode`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanceWriteMode {
+    Create = 0,
+    Append = 1,
+    Overwrite = 2,
+}
+
+/// Options controlling storage format and file/group sizing, passed to
+/// `lance_writer_create_ex` so callers aren't stuck with the hardcoded
+/// defaults `lance_writer_create` uses.
+///
+/// A zero/negative `max_rows_per_file` or a null `storage_version` means
+/// "use Lance's default" rather than an explicit override; a zero/negative
+/// `max_rows_per_group` falls back to 4096, the value `lance_writer_create`
+/// uses.
+#[repr(C)]
+pub struct LanceWriterOptions {
+    /// "2.0" or "2.1"; null or empty means the Lance default file version.
+    pub storage_version: *const c_char,
+    pub max_rows_per_group: i64,
+    pub max_rows_per_file: i64,
+    pub mode: LanceWriteMode,
+    /// Once accumulated rows reach this many, `lance_writer_write_batch`
+    /// flushes and appends to the dataset instead of buffering until close.
+    /// `<= 0` keeps the original buffer-until-close behavior.
+    pub flush_threshold_rows: i64,
+}
+
+/// Parse a storage version string into Lance's `LanceFileVersion`.
+fn parse_storage_version(s: &str) -> Result<LanceFileVersion, String> {
+    match s {
+        "2.0" => Ok(LanceFileVersion::V2_0),
+        "2.1" => Ok(LanceFileVersion::V2_1),
+        other => Err(format!(
+            "Unsupported storage_version '{}': expected \"2.0\" or \"2.1\"",
+            other
+        )),
     }
 }
 
@@ -255,10 +845,43 @@ pub struct LanceWriterHandle {
     row_count: usize,
     closed: bool,
     runtime: Runtime,
+    /// One accumulator per schema field, lazily sized from the first batch;
+    /// merged into `lance-stats:<col>` schema metadata at close.
+    column_stats: Vec<ColumnStats>,
+    /// Storage format/sizing knobs from `lance_writer_create_ex`, applied to
+    /// `WriteParams` at close time. `None` sizing fields fall back to the
+    /// previous hardcoded defaults.
+    storage_version: Option<LanceFileVersion>,
+    max_rows_per_group: Option<usize>,
+    max_rows_per_file: Option<usize>,
+    mode: WriteMode,
+    /// Rows-since-last-flush threshold; `None` means buffer until close
+    /// (the original behavior).
+    flush_threshold_rows: Option<usize>,
+    /// Rows accumulated in `batches` since the last flush.
+    rows_pending: usize,
+    /// Whether a flush has already created the dataset on disk -- once true,
+    /// subsequent flushes must use `WriteMode::Append` regardless of `mode`.
+    flushed_any: bool,
+    /// The encoding-hinted schema computed at the first flush, reused by
+    /// every later flush so fragments stay schema-compatible and so we don't
+    /// re-evaluate `EncodingStrategy` per flush (Phase 2.0c-3).
+    cached_optimized_schema: Option<Schema>,
 }
 
 impl LanceWriterHandle {
     fn new(uri: String) -> Result<Self, String> {
+        Self::new_with_options(uri, None, None, None, WriteMode::Create, None)
+    }
+
+    fn new_with_options(
+        uri: String,
+        storage_version: Option<LanceFileVersion>,
+        max_rows_per_group: Option<usize>,
+        max_rows_per_file: Option<usize>,
+        mode: WriteMode,
+        flush_threshold_rows: Option<usize>,
+    ) -> Result<Self, String> {
         let runtime = Runtime::new().map_err(|e| format!("Failed to create Tokio runtime: {}", e))?;
 
         Ok(LanceWriterHandle {
@@ -269,9 +892,137 @@ impl LanceWriterHandle {
             row_count: 0,
             closed: false,
             runtime,
+            column_stats: Vec::new(),
+            storage_version,
+            max_rows_per_group,
+            max_rows_per_file,
+            mode,
+            flush_threshold_rows,
+            rows_pending: 0,
+            flushed_any: false,
+            cached_optimized_schema: None,
         })
     }
 
+    /// Refresh the cached schema's `lance-stats:<col>` zone-map metadata from
+    /// the current (fully merged) `column_stats`.
+    ///
+    /// Under incremental flushing, `cached_optimized_schema` is frozen at the
+    /// first flush, but `column_stats` keeps accumulating over every later
+    /// `write_batch` call. Called before every flush past the first (see
+    /// `flush`) so each write commits stats merged over everything seen so
+    /// far, not just the first flush's rows -- the final flush then carries
+    /// stats merged over the whole dataset, as the original request
+    /// intended. Unlike the `lance-encoding:` hints (which pick a physical
+    /// page encoding and so can't change once fragments are written), stats
+    /// are pure metadata and safe to update on every write.
+    fn refresh_stats_metadata(&mut self) {
+        let Some(schema) = self.cached_optimized_schema.take() else {
+            return;
+        };
+        let mut metadata = schema.metadata().cloned().unwrap_or_default();
+        for (field, stats) in schema.fields().iter().zip(self.column_stats.iter()) {
+            let stats_str = format!(
+                "min={},max={},null_count={},distinct_count={}",
+                stats.min.as_ref().map(StatValue::to_metadata_string).unwrap_or_default(),
+                stats.max.as_ref().map(StatValue::to_metadata_string).unwrap_or_default(),
+                stats.null_count,
+                stats.distinct.estimate(),
+            );
+            metadata.insert(format!("lance-stats:{}", field.name()), stats_str);
+        }
+        self.cached_optimized_schema = Some(Schema::new_with_metadata(schema.fields().clone(), metadata));
+    }
+
+    /// Write any buffered batches to the dataset URI, creating it on the
+    /// first flush and appending on every subsequent one. Clears `batches`
+    /// and `rows_pending` but keeps the running `row_count`/`batch_count`.
+    /// A no-op if nothing is buffered.
+    fn flush(&mut self) -> Result<(), String> {
+        if self.batches.is_empty() {
+            return Ok(());
+        }
+
+        let uri = self.uri.clone();
+        let batches = std::mem::take(&mut self.batches);
+        let rows_in_flush = self.rows_pending;
+        self.rows_pending = 0;
+
+        // Phase 2.0c-3: compute the encoding-hinted schema once, at the
+        // first flush, and reuse it for every later flush/append so
+        // fragments stay schema-compatible and we avoid re-evaluating
+        // EncodingStrategy per flush. The `lance-stats:` entries within it
+        // are still refreshed every flush -- see `refresh_stats_metadata`.
+        if self.cached_optimized_schema.is_some() {
+            self.refresh_stats_metadata();
+        }
+        let optimized_schema = match &self.cached_optimized_schema {
+            Some(schema) => schema.clone(),
+            None => {
+                let original_schema = batches[0].schema();
+                let strategies =
+                    compute_encoding_strategies(&original_schema, &self.column_stats, self.row_count);
+                // `column_is_all_null` only sees the batches buffered in
+                // *this* flush, but the resulting schema is cached and reused
+                // for every later flush/append. Under incremental flushing
+                // (`flush_threshold_rows` set), a column that's all-null in
+                // the first flush but not in later ones would otherwise be
+                // permanently mis-hinted as "all-null" dataset-wide, so skip
+                // the hint entirely in that mode; buffer-until-close flushes
+                // see every row and can compute it safely.
+                let all_null: Vec<bool> = if self.flush_threshold_rows.is_some() {
+                    vec![false; original_schema.fields().len()]
+                } else {
+                    (0..original_schema.fields().len())
+                        .map(|i| column_is_all_null(&batches, i))
+                        .collect()
+                };
+                let schema = create_schema_with_hints(
+                    &original_schema,
+                    &strategies,
+                    &all_null,
+                    &self.column_stats,
+                );
+                self.cached_optimized_schema = Some(schema.clone());
+                schema
+            }
+        };
+
+        let write_mode = if self.flushed_any {
+            WriteMode::Append
+        } else {
+            self.mode
+        };
+        let max_rows_per_group = self.max_rows_per_group;
+        let max_rows_per_file = self.max_rows_per_file;
+        let storage_version = self.storage_version;
+
+        let result = self.runtime.block_on(async {
+            let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), optimized_schema);
+            let write_params = WriteParams {
+                max_rows_per_group: max_rows_per_group.unwrap_or(4096),
+                max_rows_per_file: max_rows_per_file
+                    .unwrap_or_else(|| WriteParams::default().max_rows_per_file),
+                mode: write_mode,
+                data_storage_version: storage_version,
+                ..Default::default()
+            };
+            lance::Dataset::write(batch_iter, &uri, write_params).await
+        });
+
+        match result {
+            Ok(_) => {
+                self.flushed_any = true;
+                eprintln!(
+                    "Lance FFI: Flushed {} rows to {} (mode: {:?})",
+                    rows_in_flush, uri, write_mode
+                );
+                Ok(())
+            }
+            Err(e) => Err(format!("Failed to write Lance dataset: {}", e)),
+        }
+    }
+
     /// Convert Arrow C Data Interface structures to RecordBatch
     ///
     /// Implements the Arrow C Data Interface specification to convert FFI_ArrowArray
@@ -290,8 +1041,22 @@ impl LanceWriterHandle {
                 .map_err(|e| format!("Failed to convert FFI_ArrowSchema to Schema: {}", e))?;
 
             // Create safe wrapper for root array
-            let safe_array = SafeArrowArray {
-                ffi: arrow_array_ptr as *mut CDataArrowArray,
+            let root_ffi = arrow_array_ptr as *mut CDataArrowArray;
+            let safe_array = SafeArrowArray { ffi: root_ffi };
+
+            // Capture the root array's release callback into a shared guard
+            // and hand ownership of it to Rust: every buffer borrowed from
+            // this batch clones the guard in, and it fires `release` exactly
+            // once, after the last clone (i.e. the last Arrow reference)
+            // drops. Zero the caller's `release` field so the C++ side's own
+            // teardown doesn't also invoke it -- the C Data Interface treats
+            // this capture as a move of release responsibility.
+            let release_guard: Arc<ReleaseGuard> = {
+                let root = &mut *root_ffi;
+                Arc::new(ReleaseGuard {
+                    release: root.release.take(),
+                    private_data: root.private_data,
+                })
             };
 
             // Import each field as separate array
@@ -311,10 +1076,26 @@ impl LanceWriterHandle {
                 let array = match field.data_type() {
                     DataType::Int64
                     | DataType::Float64
-                    | DataType::Int32 => {
-                        import_primitive_array(&child_safe, field)?
+                    | DataType::Float32
+                    | DataType::Int32
+                    | DataType::Boolean
+                    | DataType::Date32
+                    | DataType::Date64
+                    | DataType::Decimal128(_, _) => {
+                        import_primitive_array(&child_safe, field, &release_guard)?
+                    }
+                    DataType::Utf8 | DataType::LargeUtf8 => {
+                        import_string_array(&child_safe, field, &release_guard)?
+                    }
+                    DataType::Utf8View => {
+                        import_view_array(&child_safe, true, &release_guard)?
+                    }
+                    DataType::BinaryView => {
+                        import_view_array(&child_safe, false, &release_guard)?
+                    }
+                    DataType::FixedSizeList(_, _) => {
+                        import_fixed_size_list_array(&child_safe, field, &release_guard)?
                     }
-                    DataType::Utf8 => import_string_array(&child_safe, field)?,
                     dt => {
                         return Err(format!(
                             "Unsupported type for field {}: {}",
@@ -327,10 +1108,6 @@ impl LanceWriterHandle {
                 arrays.push(array);
             }
 
-            // Note: FFI_ArrowSchema and FFI_ArrowArray have private fields in Arrow 57,
-            // so we cannot access their release callbacks directly. Arrow will handle
-            // releasing these structures through the Drop trait implementation.
-
             // Create and return RecordBatch
             RecordBatch::try_new(Arc::new(schema), arrays)
                 .map_err(|e| format!("Failed to create RecordBatch: {}", e))
@@ -338,6 +1115,35 @@ impl LanceWriterHandle {
     }
 }
 
+fn lance_writer_create_impl(
+    uri_ptr: *const c_char,
+    _arrow_schema_ptr: *const c_void,
+) -> *mut LanceWriterHandle {
+    if uri_ptr.is_null() {
+        eprintln!("Lance FFI Error: uri_ptr is null");
+        return std::ptr::null_mut();
+    }
+
+    let uri = match unsafe { CStr::from_ptr(uri_ptr) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => {
+            eprintln!("Lance FFI Error: uri_ptr is not valid UTF-8");
+            return std::ptr::null_mut();
+        }
+    };
+
+    match LanceWriterHandle::new(uri.clone()) {
+        Ok(handle) => {
+            eprintln!("Lance FFI: Writer created for URI: {}", uri);
+            Box::into_raw(Box::new(handle))
+        }
+        Err(e) => {
+            eprintln!("Lance FFI Error: Failed to create writer: {}", e);
+            std::ptr::null_mut()
+        }
+    }
+}
+
 /// Create a new Lance writer for writing to the specified URI.
 ///
 /// # Arguments
@@ -354,11 +1160,44 @@ impl LanceWriterHandle {
 #[no_mangle]
 pub extern "C" fn lance_writer_create(
     uri_ptr: *const c_char,
-    _arrow_schema_ptr: *const c_void,
+    arrow_schema_ptr: *const c_void,
+) -> *mut LanceWriterHandle {
+    ffi_guard!("lance_writer_create", std::ptr::null_mut(), || {
+        lance_writer_create_impl(uri_ptr, arrow_schema_ptr)
+    })
+}
+
+/// `extern "C-unwind"` counterpart to `lance_writer_create`, available under
+/// the `c-unwind` feature (see the crate-level `compile_error!` guard) for
+/// embedders linked uniformly with `panic=unwind` who want a Rust panic to
+/// propagate as a foreign exception instead of being caught and translated
+/// at the boundary.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_create_unwind(
+    uri_ptr: *const c_char,
+    arrow_schema_ptr: *const c_void,
+) -> *mut LanceWriterHandle {
+    lance_writer_create_impl(uri_ptr, arrow_schema_ptr)
+}
+
+fn set_error_code(error_code_out: *mut c_int, code: c_int) {
+    if !error_code_out.is_null() {
+        unsafe {
+            *error_code_out = code;
+        }
+    }
+}
+
+fn lance_writer_create_ex_impl(
+    uri_ptr: *const c_char,
+    options_ptr: *const LanceWriterOptions,
+    error_code_out: *mut c_int,
 ) -> *mut LanceWriterHandle {
-    catch_unwind(AssertUnwindSafe(|| {
+    {
         if uri_ptr.is_null() {
             eprintln!("Lance FFI Error: uri_ptr is null");
+            set_error_code(error_code_out, 1);
             return std::ptr::null_mut();
         }
 
@@ -366,56 +1205,124 @@ pub extern "C" fn lance_writer_create(
             Ok(s) => s.to_string(),
             Err(_) => {
                 eprintln!("Lance FFI Error: uri_ptr is not valid UTF-8");
+                set_error_code(error_code_out, 1);
                 return std::ptr::null_mut();
             }
         };
 
-        match LanceWriterHandle::new(uri.clone()) {
-            Ok(handle) => {
-                eprintln!("Lance FFI: Writer created for URI: {}", uri);
-                Box::into_raw(Box::new(handle))
+        let (storage_version, max_rows_per_group, max_rows_per_file, mode, flush_threshold_rows) =
+            match unsafe { options_ptr.as_ref() } {
+                None => (None, None, None, WriteMode::Create, None),
+                Some(opts) => {
+                    let storage_version = if opts.storage_version.is_null() {
+                        None
+                    } else {
+                        match unsafe { CStr::from_ptr(opts.storage_version) }.to_str() {
+                            Ok("") => None,
+                            Ok(s) => match parse_storage_version(s) {
+                                Ok(v) => Some(v),
+                                Err(e) => {
+                                    eprintln!("Lance FFI Error: {}", e);
+                                    set_error_code(error_code_out, 4);
+                                    return std::ptr::null_mut();
+                                }
+                            },
+                            Err(_) => {
+                                eprintln!("Lance FFI Error: storage_version is not valid UTF-8");
+                                set_error_code(error_code_out, 3);
+                                return std::ptr::null_mut();
+                            }
+                        }
+                    };
+
+                    let max_rows_per_group = (opts.max_rows_per_group > 0)
+                        .then_some(opts.max_rows_per_group as usize);
+                    let max_rows_per_file = (opts.max_rows_per_file > 0)
+                        .then_some(opts.max_rows_per_file as usize);
+                    let mode = match opts.mode {
+                        LanceWriteMode::Create => WriteMode::Create,
+                        LanceWriteMode::Append => WriteMode::Append,
+                        LanceWriteMode::Overwrite => WriteMode::Overwrite,
+                    };
+                    let flush_threshold_rows = (opts.flush_threshold_rows > 0)
+                        .then_some(opts.flush_threshold_rows as usize);
+
+                    (storage_version, max_rows_per_group, max_rows_per_file, mode, flush_threshold_rows)
+                }
+            };
+
+        match LanceWriterHandle::new_with_options(
+            uri.clone(),
+            storage_version,
+            max_rows_per_group,
+            max_rows_per_file,
+            mode,
+            flush_threshold_rows,
+        ) {
+            Ok(handle) => {
+                eprintln!("Lance FFI: Writer created for URI: {} ({:?})", uri, storage_version);
+                Box::into_raw(Box::new(handle))
             }
             Err(e) => {
                 eprintln!("Lance FFI Error: Failed to create writer: {}", e);
+                set_error_code(error_code_out, 2);
                 std::ptr::null_mut()
             }
         }
-    }))
-    .unwrap_or_else(|_| {
-        eprintln!("Lance FFI Error: Panic in lance_writer_create");
-        std::ptr::null_mut()
-    })
+    }
 }
 
-/// Write a batch of records to the Lance dataset.
-///
-/// Imports Arrow C Data Interface structures and accumulates batches for
-/// efficient Lance dataset writing.
+/// Create a new Lance writer with explicit storage format and sizing options.
 ///
 /// # Arguments
-/// * `writer_ptr` - Pointer to LanceWriterHandle from lance_writer_create()
-/// * `arrow_array_ptr` - Pointer to Arrow C Data Interface FFI_ArrowArray struct
-/// * `arrow_schema_ptr` - Pointer to Arrow C Data Interface FFI_ArrowSchema struct
+/// * `uri_ptr` - C-string path to write to (e.g., "/tmp/dataset.lance")
+/// * `options_ptr` - Pointer to a `LanceWriterOptions` (optional; null uses all defaults)
+/// * `error_code_out` - Optional out-param written with a dedicated error code on failure
 ///
 /// # Returns
-/// 0 on success, non-zero error code on failure:
-///   1 = writer_ptr is null
-///   2 = Writer is already closed
-///   3 = arrow_array_ptr or arrow_schema_ptr is null
-///   4 = Failed to import Arrow C Data Interface
-///   7 = Panic in lance_writer_write_batch
+/// Opaque pointer to LanceWriterHandle, or null on error (see `error_code_out`):
+///   1 = uri_ptr is null or not valid UTF-8
+///   2 = Failed to create the writer (e.g. Tokio runtime init failed)
+///   3 = storage_version is not valid UTF-8
+///   4 = storage_version is not a recognized Lance file version
+///
+/// A panic returns null without writing `error_code_out` (the panic default
+/// for this entry point is `std::ptr::null_mut()`, which carries no code).
 ///
 /// # Safety
 /// The caller must:
-/// - Ensure writer_ptr is valid and not null
-/// - Not call this after lance_writer_close() has been called
+/// - Ensure uri_ptr is a valid null-terminated C string
+/// - Ensure options_ptr, if non-null, points to a valid LanceWriterOptions
+/// - Call lance_writer_destroy() on the returned pointer when done
 #[no_mangle]
-pub extern "C" fn lance_writer_write_batch(
+pub extern "C" fn lance_writer_create_ex(
+    uri_ptr: *const c_char,
+    options_ptr: *const LanceWriterOptions,
+    error_code_out: *mut c_int,
+) -> *mut LanceWriterHandle {
+    ffi_guard!("lance_writer_create_ex", std::ptr::null_mut(), || {
+        lance_writer_create_ex_impl(uri_ptr, options_ptr, error_code_out)
+    })
+}
+
+/// `extern "C-unwind"` counterpart to `lance_writer_create_ex`; see
+/// `lance_writer_create_unwind` for the rationale and linking requirement.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_create_ex_unwind(
+    uri_ptr: *const c_char,
+    options_ptr: *const LanceWriterOptions,
+    error_code_out: *mut c_int,
+) -> *mut LanceWriterHandle {
+    lance_writer_create_ex_impl(uri_ptr, options_ptr, error_code_out)
+}
+
+fn lance_writer_write_batch_impl(
     writer_ptr: *mut LanceWriterHandle,
     arrow_array_ptr: *const c_void,
     arrow_schema_ptr: *const c_void,
 ) -> c_int {
-    catch_unwind(AssertUnwindSafe(|| {
+    {
         if writer_ptr.is_null() {
             eprintln!("Lance FFI Error: writer_ptr is null");
             return 1;
@@ -450,9 +1357,21 @@ pub extern "C" fn lance_writer_write_batch(
         if writer.schema.is_none() {
             writer.schema = Some(record_batch.schema().as_ref().clone());
         }
+        if writer.column_stats.is_empty() {
+            writer.column_stats = (0..record_batch.num_columns())
+                .map(|_| ColumnStats::new())
+                .collect();
+        }
+
+        // Fold this batch into the running per-column zone-map stats
+        for (i, stats) in writer.column_stats.iter_mut().enumerate() {
+            stats.update(record_batch.column(i).as_ref());
+        }
 
         // Accumulate batch and update counters
-        writer.row_count += record_batch.num_rows();
+        let rows_in_batch = record_batch.num_rows();
+        writer.row_count += rows_in_batch;
+        writer.rows_pending += rows_in_batch;
         writer.batches.push(record_batch);
         writer.batch_count += 1;
 
@@ -464,14 +1383,309 @@ pub extern "C" fn lance_writer_write_batch(
             );
         }
 
+        // Incremental flush-and-append: once pending rows cross the
+        // configured threshold, write them out now instead of holding the
+        // whole dataset in memory until lance_writer_close.
+        if let Some(threshold) = writer.flush_threshold_rows {
+            if writer.rows_pending >= threshold {
+                if let Err(e) = writer.flush() {
+                    eprintln!("Lance FFI Error: Failed to flush buffered batches: {}", e);
+                    return 6;
+                }
+            }
+        }
+
         0 // Success
-    }))
-    .unwrap_or_else(|_| {
-        eprintln!("Lance FFI Error: Panic in lance_writer_write_batch");
-        7
+    }
+}
+
+/// Write a batch of records to the Lance dataset.
+///
+/// Imports Arrow C Data Interface structures and accumulates batches for
+/// efficient Lance dataset writing.
+///
+/// # Arguments
+/// * `writer_ptr` - Pointer to LanceWriterHandle from lance_writer_create()
+/// * `arrow_array_ptr` - Pointer to Arrow C Data Interface FFI_ArrowArray struct
+/// * `arrow_schema_ptr` - Pointer to Arrow C Data Interface FFI_ArrowSchema struct
+///
+/// # Returns
+/// 0 on success, non-zero error code on failure:
+///   1 = writer_ptr is null
+///   2 = Writer is already closed
+///   3 = arrow_array_ptr or arrow_schema_ptr is null
+///   4 = Failed to import Arrow C Data Interface
+///   6 = Failed to flush buffered batches (flush_threshold_rows exceeded)
+///   7 = Panic in lance_writer_write_batch
+///
+/// # Safety
+/// The caller must:
+/// - Ensure writer_ptr is valid and not null
+/// - Not call this after lance_writer_close() has been called
+#[no_mangle]
+pub extern "C" fn lance_writer_write_batch(
+    writer_ptr: *mut LanceWriterHandle,
+    arrow_array_ptr: *const c_void,
+    arrow_schema_ptr: *const c_void,
+) -> c_int {
+    ffi_guard!("lance_writer_write_batch", 7, || {
+        lance_writer_write_batch_impl(writer_ptr, arrow_array_ptr, arrow_schema_ptr)
     })
 }
 
+/// `extern "C-unwind"` counterpart to `lance_writer_write_batch`; see
+/// `lance_writer_create_unwind` for the rationale and linking requirement.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_write_batch_unwind(
+    writer_ptr: *mut LanceWriterHandle,
+    arrow_array_ptr: *const c_void,
+    arrow_schema_ptr: *const c_void,
+) -> c_int {
+    lance_writer_write_batch_impl(writer_ptr, arrow_array_ptr, arrow_schema_ptr)
+}
+
+/// A bounded-memory approximate distinct-value counter.
+///
+/// Tracks exact values in a `HashSet` of their hashes up to
+/// `DISTINCT_EXACT_CAP`; beyond that it degrades to a HyperLogLog-style
+/// sketch so cardinality tracking for high-cardinality columns (e.g.
+/// `l_comment`) stays O(1) memory instead of growing with row count.
+const DISTINCT_EXACT_CAP: usize = 10_000;
+const HLL_BUCKETS: usize = 128; // 2^7 registers
+
+#[derive(Debug, Clone)]
+struct HllSketch {
+    registers: [u8; HLL_BUCKETS],
+}
+
+impl HllSketch {
+    fn new() -> Self {
+        HllSketch { registers: [0; HLL_BUCKETS] }
+    }
+
+    fn insert_hash(&mut self, hash: u64) {
+        let bucket = (hash & (HLL_BUCKETS as u64 - 1)) as usize;
+        let rest = hash >> 7;
+        // Position of the lowest set bit (+1), i.e. run of trailing zeros + 1.
+        let rho = (rest.trailing_zeros() + 1).min(64) as u8;
+        if rho > self.registers[bucket] {
+            self.registers[bucket] = rho;
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = HLL_BUCKETS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        (alpha * m * m / sum).round() as u64
+    }
+}
+
+#[derive(Debug, Clone)]
+enum DistinctTracker {
+    Exact(std::collections::HashSet<u64>),
+    Approx(HllSketch),
+}
+
+impl DistinctTracker {
+    fn new() -> Self {
+        DistinctTracker::Exact(std::collections::HashSet::new())
+    }
+
+    fn insert(&mut self, hash: u64) {
+        match self {
+            DistinctTracker::Exact(seen) => {
+                seen.insert(hash);
+                if seen.len() > DISTINCT_EXACT_CAP {
+                    let mut sketch = HllSketch::new();
+                    for h in seen.iter() {
+                        sketch.insert_hash(*h);
+                    }
+                    *self = DistinctTracker::Approx(sketch);
+                }
+            }
+            DistinctTracker::Approx(sketch) => sketch.insert_hash(hash),
+        }
+    }
+
+    fn estimate(&self) -> u64 {
+        match self {
+            DistinctTracker::Exact(seen) => seen.len() as u64,
+            DistinctTracker::Approx(sketch) => sketch.estimate(),
+        }
+    }
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A min/max bound observed for a column, typed so numeric comparisons stay
+/// numeric and `Utf8` comparisons stay byte-order string comparisons.
+#[derive(Debug, Clone, PartialEq)]
+enum StatValue {
+    Int(i64),
+    Float(f64),
+    Decimal(i128),
+    Utf8(String),
+}
+
+impl StatValue {
+    fn to_metadata_string(&self) -> String {
+        match self {
+            StatValue::Int(v) => v.to_string(),
+            StatValue::Float(v) => v.to_string(),
+            StatValue::Decimal(v) => v.to_string(),
+            StatValue::Utf8(v) => v.clone(),
+        }
+    }
+}
+
+/// Incrementally accumulated zone-map statistics for a single column.
+///
+/// Updated as each `RecordBatch` arrives in `lance_writer_write_batch` and
+/// merged into `lance-stats:<col>` schema metadata at `lance_writer_close`,
+/// so Lance can prune pages on scan without a separate stats pass.
+#[derive(Debug, Clone)]
+struct ColumnStats {
+    min: Option<StatValue>,
+    max: Option<StatValue>,
+    null_count: usize,
+    distinct: DistinctTracker,
+}
+
+impl ColumnStats {
+    fn new() -> Self {
+        ColumnStats {
+            min: None,
+            max: None,
+            null_count: 0,
+            distinct: DistinctTracker::new(),
+        }
+    }
+
+    /// Fold one batch's column into the running stats. Only logically
+    /// non-null values contribute to min/max/distinct.
+    fn update(&mut self, array: &dyn Array) {
+        self.null_count += array.null_count();
+
+        if let Some(a) = array.as_any().downcast_ref::<Int32Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_int(a.value(i) as i64);
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Int64Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_int(a.value(i));
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Float64Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_float(a.value(i));
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<StringArray>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_utf8(a.value(i));
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<LargeStringArray>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_utf8(a.value(i));
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Float32Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_float(a.value(i) as f64);
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Date32Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_int(a.value(i) as i64);
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Date64Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_int(a.value(i));
+                }
+            }
+        } else if let Some(a) = array.as_any().downcast_ref::<Decimal128Array>() {
+            for i in 0..a.len() {
+                if !a.is_null(i) {
+                    self.observe_decimal(a.value(i));
+                }
+            }
+        }
+        // Other column types don't yet have a stats path; their null_count
+        // is still tracked above.
+    }
+
+    fn observe_int(&mut self, v: i64) {
+        self.distinct.insert(hash_bytes(&v.to_le_bytes()));
+
+        let update_min = matches!(&self.min, Some(StatValue::Int(m)) if v < *m) || self.min.is_none();
+        if update_min {
+            self.min = Some(StatValue::Int(v));
+        }
+        let update_max = matches!(&self.max, Some(StatValue::Int(m)) if v > *m) || self.max.is_none();
+        if update_max {
+            self.max = Some(StatValue::Int(v));
+        }
+    }
+
+    fn observe_float(&mut self, v: f64) {
+        self.distinct.insert(hash_bytes(&v.to_le_bytes()));
+
+        let update_min = matches!(&self.min, Some(StatValue::Float(m)) if v < *m) || self.min.is_none();
+        if update_min {
+            self.min = Some(StatValue::Float(v));
+        }
+        let update_max = matches!(&self.max, Some(StatValue::Float(m)) if v > *m) || self.max.is_none();
+        if update_max {
+            self.max = Some(StatValue::Float(v));
+        }
+    }
+
+    fn observe_decimal(&mut self, v: i128) {
+        self.distinct.insert(hash_bytes(&v.to_le_bytes()));
+
+        let update_min = matches!(&self.min, Some(StatValue::Decimal(m)) if v < *m) || self.min.is_none();
+        if update_min {
+            self.min = Some(StatValue::Decimal(v));
+        }
+        let update_max = matches!(&self.max, Some(StatValue::Decimal(m)) if v > *m) || self.max.is_none();
+        if update_max {
+            self.max = Some(StatValue::Decimal(v));
+        }
+    }
+
+    fn observe_utf8(&mut self, v: &str) {
+        self.distinct.insert(hash_bytes(v.as_bytes()));
+
+        // Byte-order comparison, matching Utf8's natural `Ord` on `&str`.
+        let update_min = matches!(&self.min, Some(StatValue::Utf8(m)) if v < m.as_str()) || self.min.is_none();
+        if update_min {
+            self.min = Some(StatValue::Utf8(v.to_string()));
+        }
+        let update_max = matches!(&self.max, Some(StatValue::Utf8(m)) if v > m.as_str()) || self.max.is_none();
+        if update_max {
+            self.max = Some(StatValue::Utf8(v.to_string()));
+        }
+    }
+}
+
 /// Finalize and close the Lance writer.
 ///
 /// Writes all accumulated batches to the Lance dataset as a single dataset write,
@@ -491,9 +1705,17 @@ struct EncodingStrategy {
 }
 
 impl EncodingStrategy {
-    /// Create encoding strategy for a single column
-    /// Fast-path columns (int/float/date) skip all evaluation overhead
-    fn for_column(field: &Field) -> Self {
+    /// Create encoding strategy for a single column.
+    /// Fast-path columns (int/float/date) skip all evaluation overhead.
+    /// `stats`, when available, resolves the `Utf8` dictionary-vs-fixed-width
+    /// decision from real cardinality instead of guessing.
+    ///
+    /// Under incremental flushing (`flush_threshold_rows` set), `stats` and
+    /// `row_count` are whatever `column_stats`/`row_count` held at the first
+    /// flush, since that's the only call site -- unlike `lance-stats:`
+    /// metadata, this decision can't be revised at close, because it picks
+    /// the physical page encoding for fragments already written.
+    fn for_column(field: &Field, stats: Option<&ColumnStats>, row_count: usize) -> Self {
         let (strategy, is_fast_path) = match field.data_type() {
             // Integer types: Always fixed-width, no alternatives (FAST PATH)
             DataType::Int64 | DataType::Int32 | DataType::Int16 | DataType::Int8 |
@@ -506,8 +1728,20 @@ impl EncodingStrategy {
             DataType::Decimal128(_, _) => ("fixed-width", true),
             // Date/Time: Always fixed-width (FAST PATH)
             DataType::Date32 | DataType::Date64 => ("fixed-width", true),
-            // String: Try dictionary heuristic (not fast path - needs cardinality check)
-            DataType::Utf8 | DataType::LargeUtf8 => ("dictionary", false),
+            // String: dictionary only pays off when cardinality is low
+            // relative to row count; fall back to the old guess if stats
+            // aren't available yet (e.g. an empty dataset).
+            DataType::Utf8 | DataType::LargeUtf8 => match (stats, row_count) {
+                (Some(stats), row_count) if row_count > 0 => {
+                    let distinct_ratio = stats.distinct.estimate() as f64 / row_count as f64;
+                    if distinct_ratio < 0.5 {
+                        ("dictionary", true)
+                    } else {
+                        ("variable-width", true)
+                    }
+                }
+                _ => ("dictionary", false),
+            },
             // Other types: Use default strategy
             _ => ("variable-width", false),
         };
@@ -524,10 +1758,15 @@ impl EncodingStrategy {
 /// Phase 2.0c-3: Pre-compute encoding strategies at schema creation time
 /// Instead of evaluating per-batch (1,200 times for lineitem),
 /// compute once and reuse for all batches.
-fn compute_encoding_strategies(schema: &Schema) -> Vec<EncodingStrategy> {
+fn compute_encoding_strategies(
+    schema: &Schema,
+    column_stats: &[ColumnStats],
+    row_count: usize,
+) -> Vec<EncodingStrategy> {
     let strategies: Vec<_> = schema.fields()
         .iter()
-        .map(|field| EncodingStrategy::for_column(field))
+        .enumerate()
+        .map(|(i, field)| EncodingStrategy::for_column(field, column_stats.get(i), row_count))
         .collect();
 
     // Count fast-path columns for logging
@@ -541,6 +1780,25 @@ fn compute_encoding_strategies(schema: &Schema) -> Vec<EncodingStrategy> {
     strategies
 }
 
+/// Return true if every row of `column` across all `batches` is null.
+///
+/// An empty column (no batches, or zero rows) is not considered all-null --
+/// there's nothing to encode a fast path for.
+fn column_is_all_null(batches: &[RecordBatch], column: usize) -> bool {
+    let mut saw_a_row = false;
+    for batch in batches {
+        let array = batch.column(column);
+        if array.len() == 0 {
+            continue;
+        }
+        saw_a_row = true;
+        if array.null_count() != array.len() {
+            return false;
+        }
+    }
+    saw_a_row
+}
+
 /// # Arguments
 /// * `writer_ptr` - Pointer to LanceWriterHandle from lance_writer_create()
 ///
@@ -550,15 +1808,29 @@ fn compute_encoding_strategies(schema: &Schema) -> Vec<EncodingStrategy> {
 /// Creates Arrow schema metadata with encoding hints to optimize Lance
 /// statistics computation and encoding strategy selection.
 /// These hints guide Lance's encoding decisions without requiring explicit statistics.
-fn create_schema_with_hints(schema: &Schema, strategies: &[EncodingStrategy]) -> Schema {
+/// `all_null` carries one flag per field (see `column_is_all_null`); a set flag
+/// overrides the strategy-derived hint with Lance's `"all-null"` page encoding.
+/// `column_stats` is merged in as `lance-stats:<col>` zone-map metadata so
+/// Lance can prune pages on scan.
+fn create_schema_with_hints(
+    schema: &Schema,
+    strategies: &[EncodingStrategy],
+    all_null: &[bool],
+    column_stats: &[ColumnStats],
+) -> Schema {
     let mut metadata = schema.metadata().cloned().unwrap_or_default();
 
     // Apply pre-computed strategies as encoding hints
     // Fast-path columns avoid all strategy evaluation overhead
-    for (field, strategy) in schema.fields().iter().zip(strategies.iter()) {
-        // Only add hints for fast-path columns (simple types with no alternatives)
-        // Complex types are left for Lance's adaptive strategy selection
-        if strategy.is_fast_path {
+    for (i, (field, strategy)) in schema.fields().iter().zip(strategies.iter()).enumerate() {
+        if all_null.get(i).copied().unwrap_or(false) {
+            metadata.insert(
+                format!("lance-encoding:{}", field.name()),
+                "all-null".to_string(),
+            );
+        } else if strategy.is_fast_path {
+            // Only add hints for fast-path columns (simple types with no alternatives)
+            // Complex types are left for Lance's adaptive strategy selection
             metadata.insert(
                 format!("lance-encoding:{}", field.name()),
                 strategy.strategy.clone(),
@@ -566,10 +1838,60 @@ fn create_schema_with_hints(schema: &Schema, strategies: &[EncodingStrategy]) ->
         }
     }
 
+    // Merge per-column zone-map statistics
+    for (field, stats) in schema.fields().iter().zip(column_stats.iter()) {
+        let stats_str = format!(
+            "min={},max={},null_count={},distinct_count={}",
+            stats.min.as_ref().map(StatValue::to_metadata_string).unwrap_or_default(),
+            stats.max.as_ref().map(StatValue::to_metadata_string).unwrap_or_default(),
+            stats.null_count,
+            stats.distinct.estimate(),
+        );
+        metadata.insert(format!("lance-stats:{}", field.name()), stats_str);
+    }
+
     // Create new schema with metadata hints
     Schema::new_with_metadata(schema.fields().clone(), metadata)
 }
 
+fn lance_writer_close_impl(writer_ptr: *mut LanceWriterHandle) -> c_int {
+    {
+        if writer_ptr.is_null() {
+            eprintln!("Lance FFI Error: writer_ptr is null");
+            return 1;
+        }
+
+        let writer = unsafe { &mut *writer_ptr };
+
+        if writer.closed {
+            eprintln!("Lance FFI Error: Writer is already closed");
+            return 2;
+        }
+
+        // Flush whatever remains buffered -- if flush_threshold_rows already
+        // drained every prior batch, this is just the final partial one.
+        if let Err(e) = writer.flush() {
+            eprintln!("Lance FFI Error: {}", e);
+            return 5;
+        }
+
+        if writer.flushed_any {
+            eprintln!(
+                "Lance FFI: Successfully wrote Lance dataset to: {} ({} batches, {} rows)",
+                writer.uri, writer.batch_count, writer.row_count
+            );
+        } else {
+            eprintln!(
+                "Lance FFI: Closed writer for URI: {} (no batches to write)",
+                writer.uri
+            );
+        }
+
+        writer.closed = true;
+        0 // Success
+    }
+}
+
 /// # Returns
 /// 0 on success, non-zero error code on failure:
 ///   1 = writer_ptr is null
@@ -584,7 +1906,26 @@ fn create_schema_with_hints(schema: &Schema, strategies: &[EncodingStrategy]) ->
 /// - Call lance_writer_destroy() after this function returns
 #[no_mangle]
 pub extern "C" fn lance_writer_close(writer_ptr: *mut LanceWriterHandle) -> c_int {
-    catch_unwind(AssertUnwindSafe(|| {
+    ffi_guard!("lance_writer_close", 3, || lance_writer_close_impl(writer_ptr))
+}
+
+/// `extern "C-unwind"` counterpart to `lance_writer_close`; see
+/// `lance_writer_create_unwind` for the rationale and linking requirement.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_close_unwind(writer_ptr: *mut LanceWriterHandle) -> c_int {
+    lance_writer_close_impl(writer_ptr)
+}
+
+fn lance_writer_create_index_impl(
+    writer_ptr: *mut LanceWriterHandle,
+    column_name_ptr: *const c_char,
+    distance_type_ptr: *const c_char,
+    max_level: c_int,
+    m: c_int,
+    ef_construction: c_int,
+) -> c_int {
+    {
         if writer_ptr.is_null() {
             eprintln!("Lance FFI Error: writer_ptr is null");
             return 1;
@@ -592,77 +1933,192 @@ pub extern "C" fn lance_writer_close(writer_ptr: *mut LanceWriterHandle) -> c_in
 
         let writer = unsafe { &mut *writer_ptr };
 
-        if writer.closed {
-            eprintln!("Lance FFI Error: Writer is already closed");
+        if !writer.closed {
+            eprintln!("Lance FFI Error: Writer must be closed before building an index");
             return 2;
         }
 
-        // Write all accumulated batches to Lance dataset
-        if !writer.batches.is_empty() {
-            let uri = writer.uri.clone();
-            let batches = std::mem::take(&mut writer.batches);
-            let batch_count = writer.batch_count;
-            let row_count = writer.row_count;
+        if column_name_ptr.is_null() {
+            eprintln!("Lance FFI Error: column_name_ptr is null");
+            return 3;
+        }
+        let column_name = match unsafe { CStr::from_ptr(column_name_ptr) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                eprintln!("Lance FFI Error: column_name_ptr is not valid UTF-8");
+                return 3;
+            }
+        };
 
-            // Use Tokio runtime to execute async Lance write
-            // with optimized WriteParams for better performance
-            let result = writer.runtime.block_on(async {
-                let original_schema = batches[0].schema();
+        let distance_type_str = if distance_type_ptr.is_null() {
+            "l2".to_string()
+        } else {
+            match unsafe { CStr::from_ptr(distance_type_ptr) }.to_str() {
+                Ok(s) if s.is_empty() => "l2".to_string(),
+                Ok(s) => s.to_string(),
+                Err(_) => {
+                    eprintln!("Lance FFI Error: distance_type_ptr is not valid UTF-8");
+                    return 5;
+                }
+            }
+        };
 
-                // Phase 2.0c-3: Pre-compute encoding strategies once for all batches
-                // This eliminates repeated strategy evaluation (19,200× for lineitem 6M rows ÷ 5K batch)
-                // Target: -70% on encoding strategy evaluation overhead
-                let strategies = compute_encoding_strategies(&original_schema);
+        let distance_type = match distance_type_str.as_str() {
+            "l2" => DistanceType::L2,
+            "cosine" => DistanceType::Cosine,
+            "dot" => DistanceType::Dot,
+            other => {
+                eprintln!("Lance FFI Error: Unknown distance_type: {}", other);
+                return 5;
+            }
+        };
+
+        let mut hnsw_params = HnswBuildParams::default();
+        if max_level > 0 {
+            hnsw_params = hnsw_params.max_level(max_level as u16);
+        }
+        if m > 0 {
+            hnsw_params = hnsw_params.num_edges(m as usize);
+        }
+        if ef_construction > 0 {
+            hnsw_params = hnsw_params.ef_construction(ef_construction as usize);
+        }
+        let ivf_params = IvfBuildParams::default();
+        let pq_params = PQBuildParams::default();
+
+        let uri = writer.uri.clone();
+
+        // Index build runs on the same Tokio runtime used for the write, per
+        // the lifecycle that created it. `InvalidColumn` is kept distinct
+        // from `Other` so the error code below comes from the validation
+        // branch that actually ran, instead of pattern-matching the
+        // formatted message (which a coincidentally-similar Lance error
+        // could also match).
+        enum IndexBuildError {
+            InvalidColumn(String),
+            Other(String),
+        }
 
-                // Phase 2.0c-2/2.0c-3: Apply pre-computed encoding hints
-                // Use strategies to guide Lance encoding decisions without explicit statistics
-                let optimized_schema = create_schema_with_hints(&original_schema, &strategies);
+        let result = writer.runtime.block_on(async {
+            let mut dataset = lance::Dataset::open(&uri)
+                .await
+                .map_err(|e| IndexBuildError::Other(format!("Failed to open dataset: {}", e)))?;
+
+            match dataset.schema().field(&column_name) {
+                Some(field) if matches!(field.data_type(), DataType::FixedSizeList(elem, _) if matches!(elem.data_type(), DataType::Float32 | DataType::Float64)) => {}
+                Some(_) => return Err(IndexBuildError::InvalidColumn(format!(
+                    "Column '{}' is not a FixedSizeList of floats",
+                    column_name
+                ))),
+                None => return Err(IndexBuildError::InvalidColumn(format!("Column '{}' not found", column_name))),
+            }
 
-                // Create batch iterator with optimized schema
-                let batch_iter = RecordBatchIterator::new(batches.into_iter().map(Ok), optimized_schema);
+            let index_params =
+                VectorIndexParams::ivf_hnsw_pq(distance_type, ivf_params, hnsw_params, pq_params);
 
-                // Phase 2.0c-2a: Optimized Lance configuration
-                // Increase max_rows_per_group for reduced encoding overhead
-                let write_params = WriteParams {
-                    max_rows_per_group: 4096,  // 4× default (1024) for better cache locality
-                    ..Default::default()
-                };
+            dataset
+                .create_index(&[column_name.as_str()], IndexType::Vector, None, &index_params, true)
+                .await
+                .map_err(|e| IndexBuildError::Other(format!("Failed to build vector index: {}", e)))
+        });
 
+        match result {
+            Ok(_) => {
                 eprintln!(
-                    "Lance FFI: Writing with pre-computed encoding strategies (Phase 2.0c-3)"
+                    "Lance FFI: Built vector index on column '{}' ({:?})",
+                    column_name, distance_type
                 );
-
-                lance::Dataset::write(batch_iter, &uri, write_params).await
-            });
-
-            match result {
-                Ok(_) => {
-                    eprintln!(
-                        "Lance FFI: Successfully wrote Lance dataset to: {} ({} batches, {} rows)",
-                        uri, batch_count, row_count
-                    );
-                }
-                Err(e) => {
-                    eprintln!("Lance FFI Error: Failed to write Lance dataset: {}", e);
-                    return 5;
-                }
+                0
+            }
+            Err(IndexBuildError::InvalidColumn(e)) => {
+                eprintln!("Lance FFI Error: {}", e);
+                4
+            }
+            Err(IndexBuildError::Other(e)) => {
+                eprintln!("Lance FFI Error: {}", e);
+                6
             }
-        } else {
-            eprintln!(
-                "Lance FFI: Closed writer for URI: {} (no batches to write)",
-                writer.uri
-            );
         }
+    }
+}
 
-        writer.closed = true;
-        0 // Success
-    }))
-    .unwrap_or_else(|_| {
-        eprintln!("Lance FFI Error: Panic in lance_writer_close");
-        3
+/// Build a vector (HNSW) index over a `FixedSizeList<Float32>`/`FixedSizeList<Float64>`
+/// column of an already-written dataset.
+///
+/// # Arguments
+/// * `writer_ptr` - Pointer to LanceWriterHandle from lance_writer_create(); must
+///   already have had lance_writer_close() called on it
+/// * `column_name_ptr` - C-string name of the vector column to index
+/// * `distance_type_ptr` - C-string distance metric: `"l2"`, `"cosine"`, or `"dot"`;
+///   null or empty defaults to `"l2"`
+/// * `max_level`, `m`, `ef_construction` - HNSW build parameters; a value `<= 0`
+///   leaves that parameter at Lance's default
+///
+/// # Returns
+/// 0 on success, non-zero error code on failure:
+///   1 = writer_ptr is null
+///   2 = Writer has not been closed yet (dataset not written)
+///   3 = column_name_ptr is null or not valid UTF-8
+///   4 = Named column is absent or not a FixedSizeList of floats
+///   5 = Unknown distance_type
+///   6 = Failed to build the index
+///   7 = Panic in lance_writer_create_index
+///
+/// # Safety
+/// The caller must ensure writer_ptr, column_name_ptr and distance_type_ptr
+/// (if non-null) are valid
+#[no_mangle]
+pub extern "C" fn lance_writer_create_index(
+    writer_ptr: *mut LanceWriterHandle,
+    column_name_ptr: *const c_char,
+    distance_type_ptr: *const c_char,
+    max_level: c_int,
+    m: c_int,
+    ef_construction: c_int,
+) -> c_int {
+    ffi_guard!("lance_writer_create_index", 7, || {
+        lance_writer_create_index_impl(
+            writer_ptr,
+            column_name_ptr,
+            distance_type_ptr,
+            max_level,
+            m,
+            ef_construction,
+        )
     })
 }
 
+/// `extern "C-unwind"` counterpart to `lance_writer_create_index`; see
+/// `lance_writer_create_unwind` for the rationale and linking requirement.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_create_index_unwind(
+    writer_ptr: *mut LanceWriterHandle,
+    column_name_ptr: *const c_char,
+    distance_type_ptr: *const c_char,
+    max_level: c_int,
+    m: c_int,
+    ef_construction: c_int,
+) -> c_int {
+    lance_writer_create_index_impl(
+        writer_ptr,
+        column_name_ptr,
+        distance_type_ptr,
+        max_level,
+        m,
+        ef_construction,
+    )
+}
+
+fn lance_writer_destroy_impl(writer_ptr: *mut LanceWriterHandle) -> LanceStatus {
+    if !writer_ptr.is_null() {
+        unsafe {
+            let _ = Box::from_raw(writer_ptr);
+        }
+    }
+    LanceStatus::Ok
+}
+
 /// Destroy and deallocate the Lance writer.
 ///
 /// Frees all resources associated with the writer.
@@ -671,21 +2127,102 @@ pub extern "C" fn lance_writer_close(writer_ptr: *mut LanceWriterHandle) -> c_in
 /// # Arguments
 /// * `writer_ptr` - Pointer to LanceWriterHandle from lance_writer_create()
 ///
+/// # Returns
+/// `LanceStatus::Ok` (destroying a null pointer is a no-op, not an error), or
+/// `LanceStatus::Error` if this call panicked.
+///
 /// # Safety
 /// The caller must:
 /// - Ensure writer_ptr is a valid pointer returned from lance_writer_create()
 /// - Not call this multiple times on the same pointer
 /// - Not use the writer_ptr after calling this function
 #[no_mangle]
-pub extern "C" fn lance_writer_destroy(writer_ptr: *mut LanceWriterHandle) {
-    catch_unwind(AssertUnwindSafe(|| {
-        if !writer_ptr.is_null() {
-            unsafe {
-                let _ = Box::from_raw(writer_ptr);
-            }
-        }
-    }))
-    .unwrap_or_else(|_| {
-        eprintln!("Lance FFI Error: Panic in lance_writer_destroy");
+pub extern "C" fn lance_writer_destroy(writer_ptr: *mut LanceWriterHandle) -> LanceStatus {
+    ffi_guard!("lance_writer_destroy", LanceStatus::Error, || {
+        lance_writer_destroy_impl(writer_ptr)
     })
 }
+
+/// `extern "C-unwind"` counterpart to `lance_writer_destroy`; see
+/// `lance_writer_create_unwind` for the rationale and linking requirement.
+#[cfg(feature = "c-unwind")]
+#[no_mangle]
+pub extern "C-unwind" fn lance_writer_destroy_unwind(writer_ptr: *mut LanceWriterHandle) -> LanceStatus {
+    lance_writer_destroy_impl(writer_ptr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StringArray};
+
+    #[test]
+    fn count_null_bits_all_valid() {
+        // Every bit set means every value is valid (non-null).
+        assert_eq!(count_null_bits(&[0xFF], 8), 0);
+    }
+
+    #[test]
+    fn count_null_bits_all_null() {
+        assert_eq!(count_null_bits(&[0x00], 8), 8);
+    }
+
+    #[test]
+    fn count_null_bits_respects_length_not_byte_boundary() {
+        // Bits (lsb first): 1 0 1 0 0 ... -- valid at 0 and 2, null at 1, 3, 4.
+        // Only the first 5 bits are in scope even though the byte holds 8.
+        let bitmap = [0b0000_0101];
+        assert_eq!(count_null_bits(&bitmap, 5), 3);
+    }
+
+    #[test]
+    fn parse_storage_version_known_values() {
+        assert!(matches!(parse_storage_version("2.0"), Ok(LanceFileVersion::V2_0)));
+        assert!(matches!(parse_storage_version("2.1"), Ok(LanceFileVersion::V2_1)));
+    }
+
+    #[test]
+    fn parse_storage_version_rejects_unknown() {
+        assert!(parse_storage_version("3.0").is_err());
+    }
+
+    #[test]
+    fn distinct_tracker_exact_dedupes() {
+        let mut tracker = DistinctTracker::new();
+        for v in [1u64, 2, 2, 3, 3, 3] {
+            tracker.insert(hash_bytes(&v.to_le_bytes()));
+        }
+        assert_eq!(tracker.estimate(), 3);
+    }
+
+    #[test]
+    fn distinct_tracker_degrades_to_approx_past_cap() {
+        let mut tracker = DistinctTracker::new();
+        let distinct_values = DISTINCT_EXACT_CAP as u64 + 2;
+        for v in 0..distinct_values {
+            tracker.insert(hash_bytes(&v.to_le_bytes()));
+        }
+        assert!(matches!(tracker, DistinctTracker::Approx(_)));
+        // HLL is approximate -- just check the estimate is in the right ballpark.
+        let estimate = tracker.estimate();
+        assert!(estimate > distinct_values / 2 && estimate < distinct_values * 2);
+    }
+
+    #[test]
+    fn column_stats_int_min_max_and_null_count() {
+        let mut stats = ColumnStats::new();
+        stats.update(&Int32Array::from(vec![Some(5), None, Some(-3), Some(10)]));
+        assert_eq!(stats.min, Some(StatValue::Int(-3)));
+        assert_eq!(stats.max, Some(StatValue::Int(10)));
+        assert_eq!(stats.null_count, 1);
+    }
+
+    #[test]
+    fn column_stats_utf8_uses_byte_order_not_length() {
+        let mut stats = ColumnStats::new();
+        stats.update(&StringArray::from(vec![Some("banana"), Some("Apple"), Some("cherry")]));
+        // Byte-order (ASCII) comparison: uppercase 'A' sorts before lowercase letters.
+        assert_eq!(stats.min, Some(StatValue::Utf8("Apple".to_string())));
+        assert_eq!(stats.max, Some(StatValue::Utf8("cherry".to_string())));
+    }
+}